@@ -1,21 +1,78 @@
 use std::os::unix::net::UnixDatagram;
 use std::fs;
+#[cfg(target_os = "linux")]
+use std::os::linux::net::SocketAddrExt;
+#[cfg(target_os = "linux")]
+use std::os::unix::net::SocketAddr;
+
+/// Returns the abstract namespace name if the path uses the `abstract:` prefix
+/// or a leading NUL byte (Linux abstract sockets have no filesystem entry).
+fn abstract_name(socket_path: &str) -> Option<&str> {
+    socket_path
+        .strip_prefix("abstract:")
+        .or_else(|| socket_path.strip_prefix('\0'))
+}
+
+#[cfg(target_os = "linux")]
+fn bind_abstract(name: &str) -> std::io::Result<UnixDatagram> {
+    let addr = SocketAddr::from_abstract_name(name.as_bytes())?;
+    UnixDatagram::bind_addr(&addr)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn bind_abstract(_name: &str) -> std::io::Result<UnixDatagram> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "abstract namespace sockets are only supported on Linux",
+    ))
+}
+
+#[cfg(target_os = "linux")]
+fn sender_description(addr: &SocketAddr) -> String {
+    if let Some(name) = addr.as_abstract_name() {
+        return format!("abstract:{}", String::from_utf8_lossy(name));
+    }
+    addr.as_pathname()
+        .and_then(|p| p.to_str())
+        .unwrap_or("<unknown>")
+        .to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn sender_description(addr: &std::os::unix::net::SocketAddr) -> String {
+    addr.as_pathname()
+        .and_then(|p| p.to_str())
+        .unwrap_or("<unknown>")
+        .to_string()
+}
 
 fn main() {
     println!("Starting debug Rust server...");
     
-    let socket_path = "/tmp/rust_server_debug.sock";
+    let socket_path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/tmp/rust_server_debug.sock".to_string());
+    let socket_path = socket_path.as_str();
+    let abstract_name = abstract_name(socket_path);
     
-    // Clean up existing socket
-    let _ = fs::remove_file(socket_path);
+    // Clean up existing socket (abstract sockets have no file to remove)
+    if abstract_name.is_none() {
+        let _ = fs::remove_file(socket_path);
+    }
     
     // Bind socket
-    let socket = match UnixDatagram::bind(socket_path) {
+    let bind_result = match abstract_name {
+        Some(name) => bind_abstract(name),
+        None => UnixDatagram::bind(socket_path),
+    };
+    let socket = match bind_result {
         Ok(socket) => {
             println!("Successfully bound socket to: {}", socket_path);
             
             // Verify the socket file was actually created
-            if std::path::Path::new(socket_path).exists() {
+            if abstract_name.is_some() {
+                println!("Abstract namespace socket, no socket file expected");
+            } else if std::path::Path::new(socket_path).exists() {
                 println!("Socket file confirmed to exist at: {}", socket_path);
             } else {
                 eprintln!("WARNING: Socket file does not exist despite successful bind!");
@@ -45,9 +102,7 @@ fn main() {
             Ok((size, sender_addr)) => {
                 let data = &buffer[..size];
                 let data_str = String::from_utf8_lossy(data);
-                let sender_path = sender_addr.as_pathname()
-                    .and_then(|p| p.to_str())
-                    .unwrap_or("<unknown>");
+                let sender_path = sender_description(&sender_addr);
                 
                 println!("===== RECEIVED DATA =====");
                 println!("Size: {} bytes", size);
@@ -66,6 +121,8 @@ fn main() {
     }
     
     // Clean up
-    let _ = fs::remove_file(socket_path);
+    if abstract_name.is_none() {
+        let _ = fs::remove_file(socket_path);
+    }
     println!("Debug server exiting");
 }
\ No newline at end of file